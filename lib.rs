@@ -28,19 +28,88 @@ mod house_bidding {
         [0; 32].into()
     }
 
-    #[derive(scale::Decode, scale::Encode, Eq, PartialEq)]
+    #[derive(scale::Decode, scale::Encode, Eq, PartialEq, Debug)]
     #[cfg_attr(
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
     )]
     pub enum HouseError {
         HouseNotFound,
-        CantBidFurther,
-        StillBidding,
         CantBidTwice,
         ValueTooSmall,
-        BiddingLimitNotFulfill,
         LowBidPriceThanPreviouse,
+        AuctionAlreadyStarted,
+        AuctionNotStarted,
+        NotInBiddingWindow,
+        AuctionNotYetEnded,
+        WinnerCannotClaimRefund,
+        NothingToClaim,
+        AlreadySettled,
+        TransferFailed,
+        MintFailed,
+        NftTransferFailed,
+        NotWinner,
+        SharesAlreadyOffered,
+        InvalidShareAmount,
+        NotEnoughShares,
+        InsufficientPayment,
+        NoSharesOffered,
+        NothingToWithdraw,
+        NotOwner,
+        AccessDenied,
+        InvalidTaxPercent,
+    }
+
+    /// Auction lifecycle
+    #[derive(scale::Decode, scale::Encode, Eq, PartialEq, Clone, Copy, Debug)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum AuctionState {
+        Created,
+        Started,
+        Ended,
+        Unsold,
+    }
+
+    /// Emitted when a new house is listed.
+    #[ink(event)]
+    pub struct HouseMinted {
+        #[ink(topic)]
+        house_id: HouseId,
+        #[ink(topic)]
+        owner: AccountId,
+    }
+
+    /// Emitted when a bid is accepted.
+    #[ink(event)]
+    pub struct BidPlaced {
+        #[ink(topic)]
+        house_id: HouseId,
+        #[ink(topic)]
+        bidder: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when an auction is settled and the winning bid is paid out.
+    #[ink(event)]
+    pub struct AuctionSettled {
+        #[ink(topic)]
+        house_id: HouseId,
+        #[ink(topic)]
+        winner: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when a losing bidder claims their refund.
+    #[ink(event)]
+    pub struct RefundClaimed {
+        #[ink(topic)]
+        house_id: HouseId,
+        #[ink(topic)]
+        bidder: AccountId,
+        amount: Balance,
     }
 
     /// Bidder struct
@@ -79,9 +148,19 @@ mod house_bidding {
         rooms: i32,
         special_features: Vec<String>,
         initial_price: Balance,
+        /// Minimum winning bid; if the highest bid at auction end falls
+        /// short, the house goes unsold instead of forcing a sale.
+        reserve_price: Balance,
+        /// If a valid bid lands within this many blocks of `end_block`, the
+        /// window is extended by the same amount (anti-sniping).
+        anti_snipe_window: BlockNumber,
         bidder: Vec<Bidder>,
         max_bid_price: Balance,
         winner: AccountId,
+        state: AuctionState,
+        start_block: BlockNumber,
+        end_block: BlockNumber,
+        settled: bool,
     }
 
     impl Default for House {
@@ -94,9 +173,15 @@ mod house_bidding {
                 rooms: Default::default(),
                 special_features: Vec::new(),
                 initial_price: Default::default(),
+                reserve_price: Default::default(),
+                anti_snipe_window: Default::default(),
                 bidder: Vec::new(),
                 max_bid_price: Default::default(),
                 winner: zero_address(),
+                state: AuctionState::Created,
+                start_block: Default::default(),
+                end_block: Default::default(),
+                settled: false,
             }
         }
     }
@@ -108,6 +193,15 @@ mod house_bidding {
         house_id: HouseId,
         bidder_id: BidderId,
         house: Mapping<HouseId, House>,
+        escrow: Mapping<(HouseId, AccountId), Balance>,
+        total_shares: Mapping<HouseId, u64>,
+        shares_available: Mapping<HouseId, u64>,
+        share_price: Mapping<HouseId, Balance>,
+        shares: Mapping<(HouseId, AccountId), u64>,
+        shareholders: Mapping<HouseId, Vec<AccountId>>,
+        revenues: Mapping<(HouseId, AccountId), Balance>,
+        tax: u8,
+        agents: Mapping<AccountId, ()>,
         #[storage_field]
         psp34: psp34::Data,
     }
@@ -119,6 +213,15 @@ mod house_bidding {
                 house_id: Default::default(),
                 bidder_id: Default::default(),
                 house: Mapping::default(),
+                escrow: Mapping::default(),
+                total_shares: Mapping::default(),
+                shares_available: Mapping::default(),
+                share_price: Mapping::default(),
+                shares: Mapping::default(),
+                shareholders: Mapping::default(),
+                revenues: Mapping::default(),
+                tax: Default::default(),
+                agents: Mapping::default(),
                 psp34: Default::default(),
             }
         }
@@ -127,7 +230,8 @@ mod house_bidding {
     impl HouseBidding {
         #[ink(constructor)]
         pub fn new() -> Self {
-            let instance = Self::default();
+            let mut instance = Self::default();
+            instance.owner = Self::env().caller();
             instance
         }
 
@@ -138,9 +242,15 @@ mod house_bidding {
             house_description: String,
             rooms: i32,
             initial_price: Balance,
+            reserve_price: Balance,
+            anti_snipe_window: BlockNumber,
             special_features: Vec<String>,
         ) -> Result<(), HouseError> {
             let house_owner = self.env().caller();
+            ensure!(
+                house_owner == self.owner || self.agents.get(&house_owner).is_some(),
+                HouseError::AccessDenied
+            );
             let house_id = self.next_house_id();
 
             let house = House {
@@ -151,11 +261,54 @@ mod house_bidding {
                 rooms,
                 special_features,
                 initial_price,
+                reserve_price,
+                anti_snipe_window,
                 bidder: vec![],
                 max_bid_price: 0,
                 winner: zero_address(),
+                state: AuctionState::Created,
+                start_block: 0,
+                end_block: 0,
+                settled: false,
             };
 
+            self.house.insert(&house_id, &house);
+
+            self._mint_to(house_owner, Id::U32(house_id as u32))
+                .map_err(|_| HouseError::MintFailed)?;
+
+            self.env().emit_event(HouseMinted {
+                house_id,
+                owner: house_owner,
+            });
+            Ok(())
+        }
+
+        /// Opens the auction window for `house_id`
+        #[ink(message)]
+        pub fn start_auction(
+            &mut self,
+            house_id: HouseId,
+            duration_blocks: BlockNumber,
+        ) -> Result<(), HouseError> {
+            let caller = self.env().caller();
+            let mut house = self.house.get(&house_id).ok_or(HouseError::HouseNotFound)?;
+            ensure!(
+                caller == house.house_owner
+                    || caller == self.owner
+                    || self.agents.get(&caller).is_some(),
+                HouseError::AccessDenied
+            );
+            ensure!(
+                house.state == AuctionState::Created,
+                HouseError::AuctionAlreadyStarted
+            );
+
+            let start_block = self.env().block_number();
+            house.state = AuctionState::Started;
+            house.start_block = start_block;
+            house.end_block = start_block + duration_blocks;
+
             self.house.insert(&house_id, &house);
             Ok(())
         }
@@ -169,6 +322,16 @@ mod house_bidding {
             match self.house.get(&house_id) {
                 None => return Err(HouseError::HouseNotFound),
                 Some(mut house) => {
+                    ensure!(
+                        house.state == AuctionState::Started,
+                        HouseError::AuctionNotStarted
+                    );
+                    let now = self.env().block_number();
+                    ensure!(
+                        now >= house.start_block && now < house.end_block,
+                        HouseError::NotInBiddingWindow
+                    );
+
                     ensure!(
                         bidder_amount >= house.initial_price,
                         HouseError::ValueTooSmall
@@ -190,42 +353,296 @@ mod house_bidding {
                         bidder_amount,
                     };
 
-                    let bidder_len = house.bidder.len() as i32;
-                    ensure!(bidder_len < 5, HouseError::CantBidFurther);
-
                     house.bidder.push(bidder);
 
+                    if now + house.anti_snipe_window >= house.end_block {
+                        house.end_block += house.anti_snipe_window;
+                    }
+
                     self.house.insert(&house_id, &house);
+                    self.escrow.insert(&(house_id, caller), &bidder_amount);
+
+                    self.env().emit_event(BidPlaced {
+                        house_id,
+                        bidder: caller,
+                        amount: bidder_amount,
+                    });
                 }
             };
 
             Ok(())
         }
 
+        /// Closes the bidding window and records the highest bidder as the
+        /// winner, or marks the house unsold when there are no bids or the
+        /// highest bid is below the reserve price. Payout happens in `settle`.
         #[ink(message)]
-        pub fn get_winner(&mut self, house_id: HouseId) -> Result<(), HouseError> {
-            match self.house.get(&house_id) {
-                None => return Err(HouseError::HouseNotFound),
-                Some(mut house) => {
-                    if house.bidder.len() == 5 {
-                        for bid in house.bidder.clone() {
-                            if bid.bidder_amount > house.max_bid_price {
-                                house.max_bid_price = bid.bidder_amount;
-                                house.winner = bid.bidder_account;
-
-                                self.house.insert(&house_id, &house);
-                            } else {
-                                return Err(HouseError::StillBidding);
-                            }
-                        }
-                    } else {
-                        return Err(HouseError::BiddingLimitNotFulfill);
-                    }
+        pub fn end_auction(&mut self, house_id: HouseId) -> Result<(), HouseError> {
+            let mut house = self.house.get(&house_id).ok_or(HouseError::HouseNotFound)?;
+            ensure!(
+                house.state == AuctionState::Started,
+                HouseError::AuctionNotStarted
+            );
+            ensure!(
+                self.env().block_number() >= house.end_block,
+                HouseError::AuctionNotYetEnded
+            );
+
+            let mut winner = zero_address();
+            let mut max_bid_price: Balance = 0;
+            for bid in house.bidder.clone() {
+                if bid.bidder_amount > max_bid_price {
+                    max_bid_price = bid.bidder_amount;
+                    winner = bid.bidder_account;
                 }
-            };
+            }
+            if max_bid_price == 0 || max_bid_price < house.reserve_price {
+                house.state = AuctionState::Unsold;
+                self.house.insert(&house_id, &house);
+                return Ok(());
+            }
+
+            house.state = AuctionState::Ended;
+            house.max_bid_price = max_bid_price;
+            house.winner = winner;
+
+            self.house.insert(&house_id, &house);
+            Ok(())
+        }
+
+        /// Refunds a non-winning bidder's escrowed bid once the auction has
+        /// ended. Guards against double-claims by zeroing the escrow entry
+        /// before transferring, mirroring the cancel_bid/claim_bid split.
+        #[ink(message)]
+        pub fn claim_refund(&mut self, house_id: HouseId) -> Result<(), HouseError> {
+            let caller = self.env().caller();
+            let house = self.house.get(&house_id).ok_or(HouseError::HouseNotFound)?;
+            ensure!(
+                house.state == AuctionState::Ended || house.state == AuctionState::Unsold,
+                HouseError::AuctionNotYetEnded
+            );
+            ensure!(caller != house.winner, HouseError::WinnerCannotClaimRefund);
+
+            let amount = self
+                .escrow
+                .get(&(house_id, caller))
+                .ok_or(HouseError::NothingToClaim)?;
+
+            self.escrow.remove(&(house_id, caller));
+            self.env()
+                .transfer(caller, amount)
+                .map_err(|_| HouseError::TransferFailed)?;
+
+            self.env().emit_event(RefundClaimed {
+                house_id,
+                bidder: caller,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Pays the winning bid to the seller. Callable once per auction;
+        /// the winner's own escrow entry is cleared since it is paid out
+        /// here rather than refunded.
+        #[ink(message)]
+        pub fn settle(&mut self, house_id: HouseId) -> Result<(), HouseError> {
+            let mut house = self.house.get(&house_id).ok_or(HouseError::HouseNotFound)?;
+            ensure!(
+                house.state == AuctionState::Ended,
+                HouseError::AuctionNotYetEnded
+            );
+            ensure!(!house.settled, HouseError::AlreadySettled);
+
+            let amount = house.max_bid_price;
+            let house_owner = house.house_owner;
+            let winner = house.winner;
+
+            self.env()
+                .transfer(house_owner, amount)
+                .map_err(|_| HouseError::TransferFailed)?;
+
+            self._transfer_token(&winner, Id::U32(house_id as u32), Vec::new())
+                .map_err(|_| HouseError::NftTransferFailed)?;
+
+            house.settled = true;
+            self.house.insert(&house_id, &house);
+            self.escrow.remove(&(house_id, winner));
+
+            self.env().emit_event(AuctionSettled {
+                house_id,
+                winner,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Fractionalizes a won house into shares
+        #[ink(message)]
+        pub fn offer_shares(
+            &mut self,
+            house_id: HouseId,
+            amount: u64,
+            price_per_share: Balance,
+        ) -> Result<(), HouseError> {
+            let caller = self.env().caller();
+            let house = self.house.get(&house_id).ok_or(HouseError::HouseNotFound)?;
+            ensure!(
+                house.state == AuctionState::Ended,
+                HouseError::AuctionNotYetEnded
+            );
+            ensure!(house.settled, HouseError::AuctionNotYetEnded);
+            ensure!(caller == house.winner, HouseError::NotWinner);
+            ensure!(
+                self.total_shares.get(&house_id).unwrap_or(0) == 0,
+                HouseError::SharesAlreadyOffered
+            );
+            ensure!(amount > 0, HouseError::InvalidShareAmount);
+
+            self.total_shares.insert(&house_id, &amount);
+            self.shares_available.insert(&house_id, &amount);
+            self.share_price.insert(&house_id, &price_per_share);
+            self.shares.insert(&(house_id, caller), &amount);
+            self.shareholders.insert(&house_id, &vec![caller]);
+
+            Ok(())
+        }
+
+        /// Buys shares of a house from the winner's unsold pool
+        #[ink(message, payable)]
+        pub fn buy_shares(&mut self, house_id: HouseId, amount: u64) -> Result<(), HouseError> {
+            let caller = self.env().caller();
+            let house = self.house.get(&house_id).ok_or(HouseError::HouseNotFound)?;
+            let price_per_share = self
+                .share_price
+                .get(&house_id)
+                .ok_or(HouseError::NoSharesOffered)?;
+            let available = self.shares_available.get(&house_id).unwrap_or(0);
+            ensure!(
+                amount > 0 && amount <= available,
+                HouseError::NotEnoughShares
+            );
+
+            let cost = price_per_share.saturating_mul(amount as Balance);
+            let paid = self.env().transferred_value();
+            ensure!(paid >= cost, HouseError::InsufficientPayment);
+            let excess = paid.saturating_sub(cost);
+
+            self.env()
+                .transfer(house.winner, cost)
+                .map_err(|_| HouseError::TransferFailed)?;
+            if excess > 0 {
+                self.env()
+                    .transfer(caller, excess)
+                    .map_err(|_| HouseError::TransferFailed)?;
+            }
+
+            let winner_shares = self.shares.get(&(house_id, house.winner)).unwrap_or(0);
+            self.shares
+                .insert(&(house_id, house.winner), &(winner_shares - amount));
+            self.shares_available
+                .insert(&house_id, &(available - amount));
+
+            let buyer_shares = self.shares.get(&(house_id, caller)).unwrap_or(0);
+            if buyer_shares == 0 {
+                let mut holders = self.shareholders.get(&house_id).unwrap_or_default();
+                holders.push(caller);
+                self.shareholders.insert(&house_id, &holders);
+            }
+            self.shares
+                .insert(&(house_id, caller), &(buyer_shares + amount));
+
+            Ok(())
+        }
+
+        /// Splits an incoming payment across a house's shareholders
+        #[ink(message, payable)]
+        pub fn distribute_revenue(&mut self, house_id: HouseId) -> Result<(), HouseError> {
+            let total = self
+                .total_shares
+                .get(&house_id)
+                .ok_or(HouseError::NoSharesOffered)?;
+            ensure!(total > 0, HouseError::NoSharesOffered);
+
+            let payment = self.env().transferred_value();
+            let tax_cut = payment.saturating_mul(self.tax as Balance) / 100;
+            let distributable = payment.saturating_sub(tax_cut);
+
+            let holders = self.shareholders.get(&house_id).unwrap_or_default();
+            for holder in holders {
+                let holder_shares = self.shares.get(&(house_id, holder)).unwrap_or(0);
+                let holder_revenue =
+                    distributable.saturating_mul(holder_shares as Balance) / (total as Balance);
+                let current = self.revenues.get(&(house_id, holder)).unwrap_or(0);
+                self.revenues
+                    .insert(&(house_id, holder), &(current + holder_revenue));
+            }
+
+            self.env()
+                .transfer(self.owner, tax_cut)
+                .map_err(|_| HouseError::TransferFailed)?;
+            Ok(())
+        }
+
+        /// Withdraws a shareholder's accumulated revenue for a house.
+        #[ink(message)]
+        pub fn withdraw_revenue(&mut self, house_id: HouseId) -> Result<(), HouseError> {
+            let caller = self.env().caller();
+            let amount = self
+                .revenues
+                .get(&(house_id, caller))
+                .ok_or(HouseError::NothingToWithdraw)?;
+            ensure!(amount > 0, HouseError::NothingToWithdraw);
+
+            self.env()
+                .transfer(caller, amount)
+                .map_err(|_| HouseError::TransferFailed)?;
+            self.revenues.insert(&(house_id, caller), &0);
+            Ok(())
+        }
+
+        /// Sets the revenue-distribution tax percentage
+        #[ink(message)]
+        pub fn set_tax(&mut self, tax_percent: u8) -> Result<(), HouseError> {
+            ensure!(self.env().caller() == self.owner, HouseError::NotOwner);
+            ensure!(tax_percent <= 100, HouseError::InvalidTaxPercent);
+            self.tax = tax_percent;
             Ok(())
         }
 
+        /// Approves `who` to list houses
+        #[ink(message)]
+        pub fn approve_agent(&mut self, who: AccountId) -> Result<(), HouseError> {
+            ensure!(self.env().caller() == self.owner, HouseError::NotOwner);
+            self.agents.insert(&who, &());
+            Ok(())
+        }
+
+        /// Revokes `who`'s ability to list houses
+        #[ink(message)]
+        pub fn revoke_agent(&mut self, who: AccountId) -> Result<(), HouseError> {
+            ensure!(self.env().caller() == self.owner, HouseError::NotOwner);
+            self.agents.remove(&who);
+            Ok(())
+        }
+
+        /// Transfers contract ownership to `new_owner`
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), HouseError> {
+            ensure!(self.env().caller() == self.owner, HouseError::NotOwner);
+            self.owner = new_owner;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_winner(&self, house_id: HouseId) -> Result<(AccountId, Balance), HouseError> {
+            let house = self.house.get(&house_id).ok_or(HouseError::HouseNotFound)?;
+            ensure!(
+                house.state == AuctionState::Ended,
+                HouseError::AuctionNotYetEnded
+            );
+            Ok((house.winner, house.max_bid_price))
+        }
+
         #[ink(message)]
         pub fn get_house(&self) -> Vec<House> {
             let mut house_vec: Vec<House> = Vec::new();
@@ -250,4 +667,410 @@ mod house_bidding {
             id
         }
     }
+
+    /// Exposes the standard PSP34 messages (`balance_of`, `owner_of`,
+    /// `transfer`, ...) so a house's deed is a tradable token, not just an
+    /// internal bookkeeping struct.
+    impl PSP34 for HouseBidding {}
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::{test, DefaultEnvironment};
+
+        fn accounts() -> test::DefaultAccounts<DefaultEnvironment> {
+            test::default_accounts::<DefaultEnvironment>()
+        }
+
+        fn set_caller(caller: AccountId) {
+            test::set_caller::<DefaultEnvironment>(caller);
+        }
+
+        fn set_value(value: Balance) {
+            test::set_value_transferred::<DefaultEnvironment>(value);
+        }
+
+        fn advance_blocks(n: u32) {
+            for _ in 0..n {
+                test::advance_block::<DefaultEnvironment>();
+            }
+        }
+
+        fn mint(contract: &mut HouseBidding) -> HouseId {
+            contract
+                .mint_house(
+                    String::from("Cottage"),
+                    String::from("Cozy"),
+                    3,
+                    10,
+                    0,
+                    0,
+                    vec![],
+                )
+                .unwrap();
+            0
+        }
+
+        #[ink::test]
+        fn auction_picks_the_single_highest_bidder() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+            contract.start_auction(house_id, 10).unwrap();
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+
+            set_caller(accounts.charlie);
+            set_value(30);
+            contract.bid(house_id).unwrap();
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+
+            let (winner, amount) = contract.get_winner(house_id).unwrap();
+            assert_eq!(winner, accounts.charlie);
+            assert_eq!(amount, 30);
+        }
+
+        #[ink::test]
+        fn bid_rejected_before_auction_started() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+
+            set_caller(accounts.bob);
+            set_value(20);
+            assert_eq!(contract.bid(house_id), Err(HouseError::AuctionNotStarted));
+        }
+
+        #[ink::test]
+        fn end_auction_with_no_bids_goes_unsold() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+            contract.start_auction(house_id, 10).unwrap();
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+
+            assert_eq!(
+                contract.get_winner(house_id),
+                Err(HouseError::AuctionNotYetEnded)
+            );
+        }
+
+        #[ink::test]
+        fn settle_pays_seller_and_refund_pays_losing_bidder() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+            contract.start_auction(house_id, 10).unwrap();
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+
+            set_caller(accounts.charlie);
+            set_value(30);
+            contract.bid(house_id).unwrap();
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+
+            contract.settle(house_id).unwrap();
+            assert_eq!(contract.settle(house_id), Err(HouseError::AlreadySettled));
+
+            set_caller(accounts.bob);
+            contract.claim_refund(house_id).unwrap();
+            assert_eq!(
+                contract.claim_refund(house_id),
+                Err(HouseError::NothingToClaim)
+            );
+
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.claim_refund(house_id),
+                Err(HouseError::WinnerCannotClaimRefund)
+            );
+        }
+
+        #[ink::test]
+        fn settle_mints_deed_to_owner_then_transfers_it_to_the_winner() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+            let id = Id::U32(house_id as u32);
+            assert_eq!(contract.owner_of(id.clone()), Some(accounts.alice));
+
+            contract.start_auction(house_id, 10).unwrap();
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+            contract.settle(house_id).unwrap();
+
+            assert_eq!(contract.owner_of(id), Some(accounts.bob));
+        }
+
+        fn win_and_settle(
+            accounts: &test::DefaultAccounts<DefaultEnvironment>,
+        ) -> (HouseBidding, HouseId) {
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+            contract.start_auction(house_id, 10).unwrap();
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+            contract.settle(house_id).unwrap();
+            (contract, house_id)
+        }
+
+        #[ink::test]
+        fn offer_shares_requires_settlement_and_winner() {
+            let accounts = accounts();
+            let mut contract = HouseBidding::new();
+            set_caller(accounts.alice);
+            let house_id = mint(&mut contract);
+            contract.start_auction(house_id, 10).unwrap();
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+
+            assert_eq!(
+                contract.offer_shares(house_id, 100, 1),
+                Err(HouseError::AuctionNotYetEnded)
+            );
+
+            contract.settle(house_id).unwrap();
+
+            set_caller(accounts.charlie);
+            assert_eq!(
+                contract.offer_shares(house_id, 100, 1),
+                Err(HouseError::NotWinner)
+            );
+        }
+
+        #[ink::test]
+        fn buy_shares_splits_ownership_and_refunds_overpayment() {
+            let accounts = accounts();
+            let (mut contract, house_id) = win_and_settle(&accounts);
+
+            set_caller(accounts.bob);
+            contract.offer_shares(house_id, 100, 1).unwrap();
+
+            set_caller(accounts.charlie);
+            set_value(50);
+            contract.buy_shares(house_id, 40).unwrap();
+
+            assert_eq!(
+                contract.buy_shares(house_id, 1_000),
+                Err(HouseError::NotEnoughShares)
+            );
+        }
+
+        #[ink::test]
+        fn distribute_revenue_pays_shareholders_and_taxes_the_owner() {
+            let accounts = accounts();
+            let (mut contract, house_id) = win_and_settle(&accounts);
+
+            set_caller(accounts.bob);
+            contract.offer_shares(house_id, 100, 1).unwrap();
+
+            set_caller(accounts.alice);
+            contract.set_tax(10).unwrap();
+
+            set_caller(accounts.eve);
+            set_value(200);
+            contract.distribute_revenue(house_id).unwrap();
+
+            set_caller(accounts.bob);
+            contract.withdraw_revenue(house_id).unwrap();
+            assert_eq!(
+                contract.withdraw_revenue(house_id),
+                Err(HouseError::NothingToWithdraw)
+            );
+        }
+
+        #[ink::test]
+        fn set_tax_rejects_out_of_range_percentages() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            assert_eq!(
+                contract.set_tax(101),
+                Err(HouseError::InvalidTaxPercent)
+            );
+        }
+
+        #[ink::test]
+        fn bidding_lifecycle_emits_one_event_per_step() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+            assert_eq!(test::recorded_events().count(), 1);
+
+            contract.start_auction(house_id, 10).unwrap();
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+            assert_eq!(test::recorded_events().count(), 2);
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+            contract.settle(house_id).unwrap();
+            assert_eq!(test::recorded_events().count(), 3);
+        }
+
+        #[ink::test]
+        fn only_owner_or_approved_agent_may_mint_house() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract
+                    .mint_house(String::from("Cottage"), String::from("Cozy"), 3, 10, 0, 0, vec![])
+                    .unwrap_err(),
+                HouseError::AccessDenied
+            );
+
+            set_caller(accounts.alice);
+            contract.approve_agent(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            mint(&mut contract);
+
+            set_caller(accounts.alice);
+            contract.revoke_agent(accounts.bob).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract
+                    .mint_house(String::from("Cottage"), String::from("Cozy"), 3, 10, 0, 0, vec![])
+                    .unwrap_err(),
+                HouseError::AccessDenied
+            );
+        }
+
+        #[ink::test]
+        fn only_house_owner_or_agent_may_start_its_auction() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint(&mut contract);
+
+            set_caller(accounts.bob);
+            assert_eq!(
+                contract.start_auction(house_id, 10),
+                Err(HouseError::AccessDenied)
+            );
+
+            set_caller(accounts.alice);
+            assert!(contract.start_auction(house_id, 10).is_ok());
+        }
+
+        #[ink::test]
+        fn transfer_ownership_moves_admin_rights() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            contract.transfer_ownership(accounts.bob).unwrap();
+
+            assert_eq!(
+                contract.set_tax(10),
+                Err(HouseError::NotOwner)
+            );
+
+            set_caller(accounts.bob);
+            contract.set_tax(10).unwrap();
+        }
+
+        fn mint_with(
+            contract: &mut HouseBidding,
+            reserve_price: Balance,
+            anti_snipe_window: BlockNumber,
+        ) -> HouseId {
+            contract
+                .mint_house(
+                    String::from("Cottage"),
+                    String::from("Cozy"),
+                    3,
+                    10,
+                    reserve_price,
+                    anti_snipe_window,
+                    vec![],
+                )
+                .unwrap();
+            0
+        }
+
+        #[ink::test]
+        fn below_reserve_price_goes_unsold_and_refunds() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint_with(&mut contract, 100, 0);
+            contract.start_auction(house_id, 10).unwrap();
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+
+            advance_blocks(11);
+            contract.end_auction(house_id).unwrap();
+
+            assert_eq!(
+                contract.get_winner(house_id),
+                Err(HouseError::AuctionNotYetEnded)
+            );
+
+            contract.claim_refund(house_id).unwrap();
+        }
+
+        #[ink::test]
+        fn a_late_bid_extends_the_auction_window() {
+            let accounts = accounts();
+            set_caller(accounts.alice);
+            let mut contract = HouseBidding::new();
+            let house_id = mint_with(&mut contract, 0, 5);
+            contract.start_auction(house_id, 10).unwrap();
+
+            advance_blocks(9);
+
+            set_caller(accounts.bob);
+            set_value(20);
+            contract.bid(house_id).unwrap();
+
+            advance_blocks(1);
+            assert_eq!(
+                contract.end_auction(house_id),
+                Err(HouseError::AuctionNotYetEnded)
+            );
+
+            advance_blocks(5);
+            contract.end_auction(house_id).unwrap();
+        }
+    }
 }